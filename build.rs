@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Upstream location of the pretrained Silero VAD model. Fetched once per
+/// `OUT_DIR` (i.e. once per clean build) rather than committed to the repo,
+/// since it's a ~2MB binary asset that doesn't belong in version control.
+const MODEL_URL: &str = "https://github.com/snakers4/silero-vad/raw/master/files/silero_vad.onnx";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=SILERO_VAD_MODEL_PATH");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    let dest = out_dir.join("silero_vad.onnx");
+
+    // Offline/CI builds can point this at a pre-downloaded copy instead of
+    // hitting the network.
+    if let Ok(vendored) = env::var("SILERO_VAD_MODEL_PATH") {
+        fs::copy(&vendored, &dest).unwrap_or_else(|err| {
+            panic!(
+                "failed to copy SILERO_VAD_MODEL_PATH={vendored} to {}: {err}",
+                dest.display()
+            )
+        });
+        return;
+    }
+
+    if dest.exists() {
+        return;
+    }
+
+    let mut body = Vec::new();
+    ureq::get(MODEL_URL)
+        .call()
+        .unwrap_or_else(|err| panic!("failed to download {MODEL_URL}: {err}"))
+        .into_reader()
+        .read_to_end(&mut body)
+        .unwrap_or_else(|err| panic!("failed to read model response body: {err}"));
+
+    fs::write(&dest, &body)
+        .unwrap_or_else(|err| panic!("failed to write {}: {err}", dest.display()));
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// Errors returned by [`VoiceActivityDetector`](crate::VoiceActivityDetector).
+#[derive(Debug)]
+pub enum Error {
+    /// The requested sample rate isn't one the Silero model supports.
+    UnsupportedSampleRate(u32),
+    /// The underlying ONNX session failed to initialize.
+    Session(ort::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnsupportedSampleRate(rate) => {
+                write!(f, "unsupported sample rate: {rate} (expected 8000 or 16000)")
+            }
+            Error::Session(err) => write!(f, "failed to initialize vad session: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ort::Error> for Error {
+    fn from(err: ort::Error) -> Self {
+        Error::Session(err)
+    }
+}
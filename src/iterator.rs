@@ -0,0 +1,152 @@
+use crate::label::{LabelConfig, LabelIterator};
+use crate::mono::ToMono;
+use crate::rechunk::Rechunk;
+use crate::resample::Resample;
+use crate::sample::Sample;
+use crate::vad::VoiceActivityDetector;
+
+/// Adapters that turn a plain sample iterator into a VAD pipeline.
+pub trait IteratorExt: Iterator {
+    /// Classifies the stream in chunks of `N` samples, yielding each chunk
+    /// alongside its speech probability.
+    ///
+    /// The final chunk is yielded as-is (shorter than `N`) rather than
+    /// padded or dropped.
+    fn predict<const N: usize>(self, vad: VoiceActivityDetector<N>) -> PredictIterator<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Sample,
+    {
+        PredictIterator { iter: self, vad }
+    }
+
+    /// Like [`predict`](IteratorExt::predict), but for a source that's
+    /// already chunked (e.g. the output of [`rechunk`](IteratorExt::rechunk))
+    /// instead of individual samples.
+    ///
+    /// Each chunk is classified as-is rather than re-sliced to `N`, so this
+    /// is the adapter to reach for once something else has already grouped
+    /// the stream into chunks.
+    fn predict_chunks<S, const N: usize>(
+        self,
+        vad: VoiceActivityDetector<N>,
+    ) -> PredictChunksIterator<Self, N>
+    where
+        Self: Sized + Iterator<Item = Vec<S>>,
+        S: Sample,
+    {
+        PredictChunksIterator { iter: self, vad }
+    }
+
+    /// Runs a hysteresis state machine over [`predict`](IteratorExt::predict)'s
+    /// per-chunk probabilities and coalesces them into [`Label`] segments.
+    ///
+    /// See [`LabelConfig`] for the thresholds and durations that control
+    /// onset/offset debouncing and context padding.
+    fn label<const N: usize>(
+        self,
+        vad: VoiceActivityDetector<N>,
+        config: LabelConfig,
+    ) -> LabelIterator<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Sample,
+    {
+        let sample_rate = vad.sample_rate();
+        LabelIterator::new(self.predict(vad), sample_rate, config)
+    }
+
+    /// Resamples the stream from `from_rate` to `to_rate` using band-limited
+    /// sinc interpolation, so audio recorded at an arbitrary rate (e.g. a
+    /// 44.1 kHz or 48 kHz WAV) can be fed into [`predict`](IteratorExt::predict)
+    /// at whatever rate the detector was constructed for.
+    fn resample(self, from_rate: u32, to_rate: u32) -> Resample<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Sample + Default,
+    {
+        Resample::new(self, from_rate, to_rate)
+    }
+
+    /// Downmixes an interleaved multi-channel stream (e.g. the output of
+    /// `reader.samples::<i16>()` on a stereo WAV) to mono by averaging
+    /// every group of `channels` consecutive samples.
+    ///
+    /// If the stream length isn't a multiple of `channels`, the final
+    /// frame is averaged over however many samples remain.
+    fn to_mono(self, channels: usize) -> ToMono<Self>
+    where
+        Self: Sized + Iterator<Item = i16>,
+    {
+        ToMono::new(self, channels)
+    }
+
+    /// Re-chunks a stream of arbitrarily sized fragments (e.g. 960-sample
+    /// Opus packets) into exactly-`N`-sample chunks suitable for
+    /// [`predict_chunks`](IteratorExt::predict_chunks).
+    ///
+    /// The final fragment is zero-padded up to `N` rather than dropped, so
+    /// live sources don't lose their last partial chunk when the stream
+    /// ends.
+    fn rechunk<const N: usize>(self) -> Rechunk<Self, N>
+    where
+        Self: Sized + Iterator<Item = Vec<i16>>,
+    {
+        Rechunk::new(self)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Iterator returned by [`IteratorExt::predict`].
+pub struct PredictIterator<I, const N: usize> {
+    iter: I,
+    vad: VoiceActivityDetector<N>,
+}
+
+impl<I, const N: usize> Iterator for PredictIterator<I, N>
+where
+    I: Iterator,
+    I::Item: Sample,
+{
+    type Item = (Vec<I::Item>, f32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(N).collect();
+        if chunk.is_empty() {
+            return None;
+        }
+
+        let probability = self.vad.predict(chunk.iter().copied());
+        Some((chunk, probability))
+    }
+}
+
+/// Iterator returned by [`IteratorExt::predict_chunks`].
+pub struct PredictChunksIterator<I, const N: usize> {
+    iter: I,
+    vad: VoiceActivityDetector<N>,
+}
+
+impl<I, S, const N: usize> Iterator for PredictChunksIterator<I, N>
+where
+    I: Iterator<Item = Vec<S>>,
+    S: Sample,
+{
+    type Item = (Vec<S>, f32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let chunk = self.iter.next()?;
+            // An empty chunk has nothing to classify and would panic deep
+            // inside `VoiceActivityDetector::predict`; skip it rather than
+            // trusting the upstream iterator never produces one.
+            if chunk.is_empty() {
+                continue;
+            }
+
+            let probability = self.vad.predict(chunk.iter().copied());
+            return Some((chunk, probability));
+        }
+    }
+}
@@ -0,0 +1,561 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::iterator::PredictIterator;
+use crate::sample::Sample;
+
+/// Thresholds and durations controlling [`IteratorExt::label`](crate::IteratorExt::label).
+///
+/// Durations are expressed in wall-clock time and converted to sample
+/// counts using the detector's sample rate, so the same `LabelConfig` works
+/// regardless of chunk size.
+#[derive(Debug, Clone, Copy)]
+pub struct LabelConfig {
+    /// Probability above which a silent region is considered to have
+    /// started speaking.
+    pub onset_threshold: f32,
+    /// Probability below which a speaking region is considered to have
+    /// gone silent. Kept lower than `onset_threshold` (hysteresis) so
+    /// probabilities hovering near the boundary don't chop a segment into
+    /// many tiny ones.
+    pub offset_threshold: f32,
+    /// Probability must stay below `offset_threshold` for at least this
+    /// long before an open segment is closed.
+    pub min_silence_duration: Duration,
+    /// Segments shorter than this, measured from onset to offset, are not
+    /// emitted as speech; their samples are emitted as `NonSpeech` instead.
+    pub min_speech_duration: Duration,
+    /// Amount of surrounding non-speech context to keep on either side of
+    /// a segment.
+    pub speech_pad: Duration,
+}
+
+impl Default for LabelConfig {
+    fn default() -> Self {
+        Self {
+            onset_threshold: 0.5,
+            offset_threshold: 0.35,
+            min_silence_duration: Duration::from_millis(100),
+            min_speech_duration: Duration::from_millis(250),
+            speech_pad: Duration::from_millis(30),
+        }
+    }
+}
+
+/// A coalesced region produced by [`IteratorExt::label`](crate::IteratorExt::label).
+#[derive(Debug, Clone)]
+pub enum Label<S> {
+    /// A speech region, padded with `speech_pad` worth of context on
+    /// either side where available.
+    Speech {
+        start_sample: usize,
+        end_sample: usize,
+        samples: Vec<S>,
+    },
+    /// Everything between (or before/after) speech regions.
+    NonSpeech {
+        start_sample: usize,
+        end_sample: usize,
+        samples: Vec<S>,
+    },
+}
+
+/// The hysteresis state machine shared by [`LabelIterator`] and
+/// [`LabelStream`](crate::label::LabelStream).
+///
+/// It consumes one classified chunk at a time and accumulates completed
+/// [`Label`]s into a queue, so the wrapping iterator/stream only has to
+/// drain that queue and pull another chunk when it's empty.
+struct LabelEngine<S> {
+    onset_threshold: f32,
+    offset_threshold: f32,
+    min_silence_samples: usize,
+    min_speech_samples: usize,
+    pad_samples: usize,
+    position: usize,
+    state: State<S>,
+    ready: VecDeque<Label<S>>,
+}
+
+enum State<S> {
+    /// Accumulating a non-speech run. `start_sample` is where it began.
+    Idle { start_sample: usize, samples: Vec<S> },
+    /// Accumulating a speech run that started at `start_sample`.
+    /// `silence_run` counts the trailing samples that have been below
+    /// `offset_threshold` so far. `leading_pad` is how many samples at
+    /// the front of `samples` are non-speech context carried over from
+    /// `trigger`, not genuine detected speech.
+    Triggered {
+        start_sample: usize,
+        samples: Vec<S>,
+        silence_run: usize,
+        leading_pad: usize,
+    },
+}
+
+impl<S: Sample> LabelEngine<S> {
+    fn new(sample_rate: u32, config: LabelConfig) -> Self {
+        let as_samples = |d: Duration| {
+            (d.as_secs_f64() * sample_rate as f64).round() as usize
+        };
+
+        Self {
+            onset_threshold: config.onset_threshold,
+            offset_threshold: config.offset_threshold,
+            min_silence_samples: as_samples(config.min_silence_duration),
+            min_speech_samples: as_samples(config.min_speech_duration),
+            pad_samples: as_samples(config.speech_pad),
+            position: 0,
+            state: State::Idle {
+                start_sample: 0,
+                samples: Vec::new(),
+            },
+            ready: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, chunk: Vec<S>, probability: f32) {
+        let len = chunk.len();
+
+        match &mut self.state {
+            State::Idle { .. } => {
+                if probability > self.onset_threshold {
+                    // `trigger` splits the non-speech run accumulated so
+                    // far into the padding it keeps and everything before
+                    // it; the chunk that caused the trigger is genuine
+                    // speech and always goes in whole, after that split.
+                    self.trigger();
+                    if let State::Triggered { samples, .. } = &mut self.state {
+                        samples.extend(chunk);
+                    }
+                } else if let State::Idle { samples, .. } = &mut self.state {
+                    samples.extend(chunk);
+                }
+            }
+            State::Triggered {
+                samples,
+                silence_run,
+                ..
+            } => {
+                samples.extend(chunk);
+                if probability < self.offset_threshold {
+                    *silence_run += len;
+                } else {
+                    *silence_run = 0;
+                }
+            }
+        }
+
+        if let State::Triggered { silence_run, .. } = &self.state {
+            if *silence_run >= self.min_silence_samples {
+                self.close();
+            }
+        }
+
+        self.position += len;
+    }
+
+    /// Transitions `Idle` -> `Triggered`, splitting off `pad_samples` worth
+    /// of trailing non-speech to seed the new segment's leading padding.
+    fn trigger(&mut self) {
+        let State::Idle { start_sample, samples } = std::mem::replace(
+            &mut self.state,
+            State::Idle {
+                start_sample: self.position,
+                samples: Vec::new(),
+            },
+        ) else {
+            unreachable!("trigger is only called from the Idle state")
+        };
+
+        let split = samples.len().saturating_sub(self.pad_samples);
+        let (kept, padding) = {
+            let mut samples = samples;
+            let padding = samples.split_off(split);
+            (samples, padding)
+        };
+
+        if !kept.is_empty() {
+            self.ready.push_back(Label::NonSpeech {
+                start_sample,
+                end_sample: start_sample + kept.len(),
+                samples: kept,
+            });
+        }
+
+        let leading_pad = padding.len();
+        self.state = State::Triggered {
+            start_sample: start_sample + split,
+            samples: padding,
+            silence_run: 0,
+            leading_pad,
+        };
+    }
+
+    /// Transitions `Triggered` -> `Idle`, trimming the segment back to
+    /// `pad_samples` worth of trailing context, or folding it entirely back
+    /// into non-speech if the speech run didn't meet `min_speech_samples`.
+    fn close(&mut self) {
+        let State::Triggered {
+            start_sample,
+            samples,
+            silence_run,
+            leading_pad,
+        } = std::mem::replace(
+            &mut self.state,
+            State::Idle {
+                start_sample: self.position,
+                samples: Vec::new(),
+            },
+        )
+        else {
+            unreachable!("close is only called from the Triggered state")
+        };
+
+        // `leading_pad` is carried-over context, not genuine speech, so it
+        // must not count toward `min_speech_samples` on its own.
+        let genuine_speech_samples = samples.len() - leading_pad - silence_run;
+
+        if genuine_speech_samples >= self.min_speech_samples {
+            let kept_trailing = silence_run.min(self.pad_samples);
+            let split = leading_pad + genuine_speech_samples + kept_trailing;
+
+            let mut samples = samples;
+            let leftover = samples.split_off(split);
+
+            self.ready.push_back(Label::Speech {
+                start_sample,
+                end_sample: start_sample + samples.len(),
+                samples,
+            });
+
+            self.state = State::Idle {
+                start_sample: start_sample + split,
+                samples: leftover,
+            };
+        } else {
+            // Too short to count as speech: fold the whole buffered run
+            // (including the leading padding carried over at trigger time)
+            // back into non-speech instead of silently dropping it.
+            let end_sample = start_sample + samples.len();
+            self.ready.push_back(Label::NonSpeech {
+                start_sample,
+                end_sample,
+                samples,
+            });
+
+            self.state = State::Idle {
+                start_sample: end_sample,
+                samples: Vec::new(),
+            };
+        }
+    }
+
+    /// Flushes whatever segment is still open when the underlying stream
+    /// ends, so trailing audio is never silently dropped.
+    fn flush(&mut self) {
+        match std::mem::replace(
+            &mut self.state,
+            State::Idle {
+                start_sample: self.position,
+                samples: Vec::new(),
+            },
+        ) {
+            State::Idle { start_sample, samples } => {
+                if !samples.is_empty() {
+                    self.ready.push_back(Label::NonSpeech {
+                        start_sample,
+                        end_sample: start_sample + samples.len(),
+                        samples,
+                    });
+                }
+            }
+            State::Triggered {
+                start_sample,
+                mut samples,
+                silence_run,
+                leading_pad,
+            } => {
+                // `leading_pad` is carried-over context, not genuine
+                // speech, so it must not count toward `min_speech_samples`
+                // on its own; neither does the trailing silence run, which
+                // is trimmed to `pad_samples` as in `close`.
+                let genuine_speech_samples = samples.len() - leading_pad - silence_run;
+                if genuine_speech_samples >= self.min_speech_samples {
+                    let split = leading_pad + genuine_speech_samples + silence_run.min(self.pad_samples);
+                    let leftover = samples.split_off(split);
+                    self.ready.push_back(Label::Speech {
+                        start_sample,
+                        end_sample: start_sample + split,
+                        samples,
+                    });
+                    if !leftover.is_empty() {
+                        self.ready.push_back(Label::NonSpeech {
+                            start_sample: start_sample + split,
+                            end_sample: start_sample + split + leftover.len(),
+                            samples: leftover,
+                        });
+                    }
+                } else {
+                    self.ready.push_back(Label::NonSpeech {
+                        start_sample,
+                        end_sample: start_sample + samples.len(),
+                        samples,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`IteratorExt::label`](crate::IteratorExt::label).
+pub struct LabelIterator<I, const N: usize>
+where
+    I: Iterator,
+    I::Item: Sample,
+{
+    chunks: PredictIterator<I, N>,
+    engine: LabelEngine<I::Item>,
+    done: bool,
+}
+
+impl<I, const N: usize> LabelIterator<I, N>
+where
+    I: Iterator,
+    I::Item: Sample,
+{
+    pub(crate) fn new(chunks: PredictIterator<I, N>, sample_rate: u32, config: LabelConfig) -> Self {
+        Self {
+            chunks,
+            engine: LabelEngine::new(sample_rate, config),
+            done: false,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for LabelIterator<I, N>
+where
+    I: Iterator,
+    I::Item: Sample,
+{
+    type Item = Label<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(label) = self.engine.ready.pop_front() {
+                return Some(label);
+            }
+
+            if self.done {
+                return None;
+            }
+
+            match self.chunks.next() {
+                Some((chunk, probability)) => self.engine.push(chunk, probability),
+                None => {
+                    self.engine.flush();
+                    self.done = true;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_impl {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use tokio_stream::Stream;
+
+    use super::{Label, LabelConfig, LabelEngine};
+    use crate::sample::Sample;
+    use crate::stream::PredictStream;
+
+    /// Stream returned by [`StreamExt::label`](crate::StreamExt::label).
+    pub struct LabelStream<T, const N: usize>
+    where
+        T: Stream,
+        T::Item: Sample,
+    {
+        chunks: PredictStream<T, N>,
+        engine: LabelEngine<T::Item>,
+        done: bool,
+    }
+
+    impl<T, const N: usize> LabelStream<T, N>
+    where
+        T: Stream,
+        T::Item: Sample,
+    {
+        pub(crate) fn new(chunks: PredictStream<T, N>, sample_rate: u32, config: LabelConfig) -> Self {
+            Self {
+                chunks,
+                engine: LabelEngine::new(sample_rate, config),
+                done: false,
+            }
+        }
+    }
+
+    impl<T, const N: usize> Stream for LabelStream<T, N>
+    where
+        T: Stream + Unpin,
+        T::Item: Sample,
+    {
+        type Item = Label<T::Item>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+
+            loop {
+                if let Some(label) = this.engine.ready.pop_front() {
+                    return Poll::Ready(Some(label));
+                }
+
+                if this.done {
+                    return Poll::Ready(None);
+                }
+
+                match Pin::new(&mut this.chunks).poll_next(cx) {
+                    Poll::Ready(Some((chunk, probability))) => this.engine.push(chunk, probability),
+                    Poll::Ready(None) => {
+                        this.engine.flush();
+                        this.done = true;
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_impl::LabelStream;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A sample rate of 1 makes `Duration::from_secs(n)` convert to exactly
+    // `n` samples, so the hysteresis math below can be checked against
+    // plain sample counts instead of fractional durations.
+    fn engine(min_silence: u64, min_speech: u64, pad: u64) -> LabelEngine<i16> {
+        LabelEngine::new(
+            1,
+            LabelConfig {
+                onset_threshold: 0.5,
+                offset_threshold: 0.3,
+                min_silence_duration: Duration::from_secs(min_silence),
+                min_speech_duration: Duration::from_secs(min_speech),
+                speech_pad: Duration::from_secs(pad),
+            },
+        )
+    }
+
+    #[test]
+    fn coalesces_speech_with_padding_and_flushes_trailing_silence() {
+        let mut engine = engine(2, 3, 1);
+
+        for _ in 0..5 {
+            engine.push(vec![0], 0.1);
+        }
+        // onset: splits the idle run into kept history plus one sample of
+        // leading padding, and the three triggering chunks become genuine
+        // speech, meeting `min_speech_samples` on their own.
+        for _ in 0..3 {
+            engine.push(vec![0], 0.9);
+        }
+        // two silent chunks push `silence_run` up to `min_silence_samples`,
+        // closing the segment with one sample of trailing padding kept.
+        for _ in 0..2 {
+            engine.push(vec![0], 0.2);
+        }
+        engine.flush();
+
+        let labels: Vec<_> = engine.ready.drain(..).collect();
+        assert_eq!(labels.len(), 3);
+
+        match &labels[0] {
+            Label::NonSpeech {
+                start_sample,
+                end_sample,
+                ..
+            } => assert_eq!((*start_sample, *end_sample), (0, 4)),
+            other => panic!("expected leading NonSpeech, got {other:?}"),
+        }
+        match &labels[1] {
+            Label::Speech {
+                start_sample,
+                end_sample,
+                ..
+            } => assert_eq!((*start_sample, *end_sample), (4, 9)),
+            other => panic!("expected Speech, got {other:?}"),
+        }
+        match &labels[2] {
+            Label::NonSpeech {
+                start_sample,
+                end_sample,
+                ..
+            } => assert_eq!((*start_sample, *end_sample), (9, 10)),
+            other => panic!("expected trailing NonSpeech from flush, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn discards_speech_bursts_shorter_than_min_speech_duration() {
+        let mut engine = engine(2, 3, 1);
+
+        for _ in 0..3 {
+            engine.push(vec![0], 0.1);
+        }
+        engine.push(vec![0], 0.9);
+        // the burst is a single sample of genuine speech before two silent
+        // samples close it again, short of the 3-sample
+        // `min_speech_samples` threshold.
+        for _ in 0..2 {
+            engine.push(vec![0], 0.2);
+        }
+
+        let labels: Vec<_> = engine.ready.drain(..).collect();
+        assert_eq!(labels.len(), 2);
+        assert!(matches!(labels[0], Label::NonSpeech { .. }));
+        match &labels[1] {
+            Label::NonSpeech {
+                start_sample,
+                end_sample,
+                ..
+            } => assert_eq!((*start_sample, *end_sample), (2, 6)),
+            other => panic!("expected the short burst folded back into NonSpeech, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn leading_padding_does_not_count_toward_min_speech_duration() {
+        // `speech_pad` (5) is large enough that, added to the single
+        // genuinely triggering sample, it would clear `min_speech_samples`
+        // (3) on its own if the padding were miscounted as detected speech.
+        let mut engine = engine(2, 3, 5);
+
+        for _ in 0..6 {
+            engine.push(vec![0], 0.1);
+        }
+        engine.push(vec![0], 0.9);
+        for _ in 0..2 {
+            engine.push(vec![0], 0.2);
+        }
+
+        let labels: Vec<_> = engine.ready.drain(..).collect();
+        assert_eq!(labels.len(), 2);
+        assert!(matches!(labels[0], Label::NonSpeech { .. }));
+        match &labels[1] {
+            Label::NonSpeech {
+                start_sample,
+                end_sample,
+                ..
+            } => assert_eq!((*start_sample, *end_sample), (1, 9)),
+            other => panic!(
+                "expected the 1-sample burst folded back into NonSpeech despite padding, got {other:?}"
+            ),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! A Silero-based voice activity detector with streaming adapters for
+//! plain [`Iterator`]s and, behind the `async` feature, [`tokio_stream`]
+//! [`Stream`](tokio_stream::Stream)s.
+
+mod error;
+mod iterator;
+mod label;
+mod mono;
+mod rechunk;
+mod resample;
+mod sample;
+#[cfg(feature = "async")]
+mod stream;
+mod vad;
+
+pub use error::Error;
+pub use iterator::{IteratorExt, PredictChunksIterator, PredictIterator};
+pub use label::{Label, LabelConfig, LabelIterator};
+#[cfg(feature = "async")]
+pub use label::LabelStream;
+pub use mono::ToMono;
+#[cfg(feature = "async")]
+pub use mono::ToMonoStream;
+pub use rechunk::Rechunk;
+#[cfg(feature = "async")]
+pub use rechunk::RechunkStream;
+pub use resample::Resample;
+#[cfg(feature = "async")]
+pub use resample::ResampleStream;
+pub use sample::Sample;
+#[cfg(feature = "async")]
+pub use stream::{PredictChunksStream, PredictStream, StreamExt};
+pub use vad::VoiceActivityDetector;
@@ -0,0 +1,135 @@
+/// Iterator returned by [`IteratorExt::to_mono`](crate::IteratorExt::to_mono).
+///
+/// Averages every group of `channels` consecutive samples from an
+/// interleaved stream (e.g. `reader.samples::<i16>()` from a stereo
+/// [`hound`](https://docs.rs/hound) WAV) into a single mono sample.
+pub struct ToMono<I> {
+    iter: I,
+    channels: usize,
+}
+
+impl<I> ToMono<I>
+where
+    I: Iterator<Item = i16>,
+{
+    pub(crate) fn new(iter: I, channels: usize) -> Self {
+        assert!(channels > 0, "channels must be at least 1");
+        Self { iter, channels }
+    }
+}
+
+impl<I> Iterator for ToMono<I>
+where
+    I: Iterator<Item = i16>,
+{
+    type Item = i16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut sum = 0i64;
+        let mut count = 0i64;
+
+        for _ in 0..self.channels {
+            match self.iter.next() {
+                Some(sample) => {
+                    sum += sample as i64;
+                    count += 1;
+                }
+                None => break,
+            }
+        }
+
+        // The final frame may be incomplete if the source has a trailing
+        // partial frame; average whatever samples are actually present
+        // rather than treating the missing ones as silence.
+        if count == 0 {
+            return None;
+        }
+
+        Some((sum / count) as i16)
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_impl {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use tokio_stream::Stream;
+
+    /// Stream returned by [`StreamExt::to_mono`](crate::StreamExt::to_mono).
+    pub struct ToMonoStream<T> {
+        stream: T,
+        channels: usize,
+        sum: i64,
+        count: i64,
+    }
+
+    impl<T> ToMonoStream<T>
+    where
+        T: Stream<Item = i16>,
+    {
+        pub(crate) fn new(stream: T, channels: usize) -> Self {
+            assert!(channels > 0, "channels must be at least 1");
+            Self {
+                stream,
+                channels,
+                sum: 0,
+                count: 0,
+            }
+        }
+    }
+
+    impl<T> Stream for ToMonoStream<T>
+    where
+        T: Stream<Item = i16> + Unpin,
+    {
+        type Item = i16;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+
+            while this.count < this.channels as i64 {
+                match Pin::new(&mut this.stream).poll_next(cx) {
+                    Poll::Ready(Some(sample)) => {
+                        this.sum += sample as i64;
+                        this.count += 1;
+                    }
+                    Poll::Ready(None) => {
+                        if this.count == 0 {
+                            return Poll::Ready(None);
+                        }
+                        break;
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            let average = (this.sum / this.count) as i16;
+            this.sum = 0;
+            this.count = 0;
+            Poll::Ready(Some(average))
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_impl::ToMonoStream;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn averages_full_frames() {
+        let mono: Vec<i16> = ToMono::new(vec![2i16, 4, 6, 8].into_iter(), 2).collect();
+        assert_eq!(mono, vec![3, 7]);
+    }
+
+    #[test]
+    fn averages_incomplete_trailing_frame_over_remaining_samples() {
+        // 3 samples over 2 channels: one full frame, then a trailing
+        // frame with only a single sample to average.
+        let mono: Vec<i16> = ToMono::new(vec![1i16, 3, 5].into_iter(), 2).collect();
+        assert_eq!(mono, vec![2, 5]);
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::VecDeque;
+
+/// Iterator returned by [`IteratorExt::rechunk`](crate::IteratorExt::rechunk).
+///
+/// Concatenates arbitrarily sized `Vec<i16>` fragments (e.g. 960-sample
+/// Opus packets from a voice call) through an internal buffer and emits
+/// exactly-`N`-sample chunks, so real-time sources don't have to buffer and
+/// re-slice by hand before calling
+/// [`predict_chunks`](crate::IteratorExt::predict_chunks).
+///
+/// The final chunk is zero-padded up to `N` rather than dropped, so
+/// trailing audio shorter than one chunk still gets classified.
+pub struct Rechunk<I, const N: usize> {
+    iter: I,
+    buffer: VecDeque<i16>,
+    done: bool,
+}
+
+impl<I, const N: usize> Rechunk<I, N>
+where
+    I: Iterator<Item = Vec<i16>>,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N > 0, "N must be at least 1");
+        Self {
+            iter,
+            buffer: VecDeque::with_capacity(N),
+            done: false,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for Rechunk<I, N>
+where
+    I: Iterator<Item = Vec<i16>>,
+{
+    type Item = Vec<i16>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        while self.buffer.len() < N {
+            match self.iter.next() {
+                Some(fragment) => self.buffer.extend(fragment),
+                None => {
+                    self.done = true;
+                    if self.buffer.is_empty() {
+                        return None;
+                    }
+                    self.buffer.resize(N, 0);
+                    break;
+                }
+            }
+        }
+
+        Some(self.buffer.drain(..N).collect())
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_impl {
+    use std::collections::VecDeque;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use tokio_stream::Stream;
+
+    /// Stream returned by [`StreamExt::rechunk`](crate::StreamExt::rechunk).
+    pub struct RechunkStream<T, const N: usize> {
+        stream: T,
+        buffer: VecDeque<i16>,
+        done: bool,
+    }
+
+    impl<T, const N: usize> RechunkStream<T, N>
+    where
+        T: Stream<Item = Vec<i16>>,
+    {
+        pub(crate) fn new(stream: T) -> Self {
+            assert!(N > 0, "N must be at least 1");
+            Self {
+                stream,
+                buffer: VecDeque::with_capacity(N),
+                done: false,
+            }
+        }
+    }
+
+    impl<T, const N: usize> Stream for RechunkStream<T, N>
+    where
+        T: Stream<Item = Vec<i16>> + Unpin,
+    {
+        type Item = Vec<i16>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            while this.buffer.len() < N {
+                match Pin::new(&mut this.stream).poll_next(cx) {
+                    Poll::Ready(Some(fragment)) => this.buffer.extend(fragment),
+                    Poll::Ready(None) => {
+                        this.done = true;
+                        if this.buffer.is_empty() {
+                            return Poll::Ready(None);
+                        }
+                        this.buffer.resize(N, 0);
+                        break;
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            Poll::Ready(Some(this.buffer.drain(..N).collect()))
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_impl::RechunkStream;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concatenates_fragments_into_exact_chunks() {
+        let chunks: Vec<Vec<i16>> = Rechunk::<_, 4>::new(
+            vec![vec![1, 2, 3], vec![4, 5, 6]].into_iter(),
+        )
+        .collect();
+
+        assert_eq!(chunks, vec![vec![1, 2, 3, 4], vec![5, 6, 0, 0]]);
+    }
+
+    #[test]
+    fn zero_pads_the_final_short_chunk() {
+        let chunks: Vec<Vec<i16>> =
+            Rechunk::<_, 4>::new(vec![vec![1, 2]].into_iter()).collect();
+
+        assert_eq!(chunks, vec![vec![1, 2, 0, 0]]);
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::VecDeque;
+
+use crate::sample::Sample;
+
+/// Half the number of input samples kept in the ring when evaluating the
+/// sinc kernel: the ring holds the `2 * KERNEL_HALF_WIDTH` most recent
+/// samples, all at or behind the fractional read position. Higher values
+/// trade CPU for a sharper stopband; 16 matches what the reference Silero
+/// pipelines use for offline resampling.
+const KERNEL_HALF_WIDTH: usize = 16;
+
+/// Windowed-sinc interpolation kernel, evaluated at a fractional offset
+/// `x` (in input samples) from the tap it's being applied to.
+///
+/// The ring only ever holds past samples, so `x` runs from `0` at the tap
+/// nearest the read position out to `2 * KERNEL_HALF_WIDTH` at the oldest,
+/// staleest tap, rather than the `[-KERNEL_HALF_WIDTH, KERNEL_HALF_WIDTH]`
+/// a centered window would assume. The window below is shifted to match
+/// that one-sided range: it peaks at `x == 0` and decays to zero by
+/// `x == 2 * KERNEL_HALF_WIDTH`.
+fn sinc_kernel(x: f32) -> f32 {
+    if x.abs() < 1e-7 {
+        return 1.0;
+    }
+
+    let pi_x = std::f32::consts::PI * x;
+    let sinc = pi_x.sin() / pi_x;
+
+    // Blackman window over the kernel's one-sided support, keeping the
+    // stalest tap at `2 * KERNEL_HALF_WIDTH` from ringing.
+    let half_width = KERNEL_HALF_WIDTH as f32;
+    let n = 0.5 + x / (4.0 * half_width);
+    let window = 0.42 - 0.5 * (2.0 * std::f32::consts::PI * n).cos()
+        + 0.08 * (4.0 * std::f32::consts::PI * n).cos();
+
+    sinc * window
+}
+
+/// What a [`ResampleCore`] step produced.
+enum Step {
+    /// Pull one more sample from the source and feed it in before
+    /// stepping again.
+    NeedMore,
+    /// The source is drained and every output sample it could still
+    /// contribute has been emitted.
+    Done,
+    /// One interpolated output sample.
+    Emit(f32),
+}
+
+/// The ring-buffer/fractional-position resampling logic shared by
+/// [`Resample`] and [`ResampleStream`](crate::resample::ResampleStream).
+///
+/// Holds a circular buffer of the last `2 * KERNEL_HALF_WIDTH` input
+/// samples, primed with zeros, and a fractional read position `pos`
+/// advanced by `ratio = from_rate / to_rate` per output sample. Memory use
+/// is bounded regardless of input length since the buffer never grows.
+struct ResampleCore<S> {
+    ratio: f64,
+    pos: f64,
+    ring: VecDeque<S>,
+    input_index: i64,
+    exhausted: bool,
+}
+
+impl<S: Sample + Default> ResampleCore<S> {
+    fn new(from_rate: u32, to_rate: u32) -> Self {
+        let mut ring = VecDeque::with_capacity(2 * KERNEL_HALF_WIDTH);
+        ring.resize(2 * KERNEL_HALF_WIDTH, S::default());
+
+        Self {
+            ratio: from_rate as f64 / to_rate as f64,
+            // The ring is primed with zeros representing the (nonexistent)
+            // samples before index 0, so the first output can be produced
+            // as soon as the real sample at index 0 has been pulled in.
+            pos: 0.0,
+            ring,
+            input_index: -1,
+            exhausted: false,
+        }
+    }
+
+    /// Feeds in the next source sample, or marks the source exhausted.
+    fn feed(&mut self, sample: Option<S>) {
+        match sample {
+            Some(sample) => {
+                self.ring.pop_front();
+                self.ring.push_back(sample);
+                self.input_index += 1;
+            }
+            None => self.exhausted = true,
+        }
+    }
+
+    fn step(&mut self) -> Step {
+        if !self.exhausted && (self.input_index as f64) < self.pos.ceil() {
+            return Step::NeedMore;
+        }
+
+        // Once the source is drained, keep producing output samples only
+        // as long as the read position still falls within the sinc
+        // kernel's one-sided support (it only decays to zero by
+        // `2 * KERNEL_HALF_WIDTH` past the newest buffered tap); past
+        // that there's nothing left to interpolate.
+        if self.exhausted && self.pos > self.input_index as f64 + 2.0 * KERNEL_HALF_WIDTH as f64 {
+            return Step::Done;
+        }
+
+        let center = self.input_index as f64 - 2.0 * KERNEL_HALF_WIDTH as f64 + 1.0;
+        let mut acc = 0.0f32;
+        for (i, sample) in self.ring.iter().enumerate() {
+            let tap_pos = center + i as f64;
+            acc += sample.to_f32() * sinc_kernel((self.pos - tap_pos) as f32);
+        }
+
+        self.pos += self.ratio;
+        Step::Emit(acc)
+    }
+}
+
+/// Iterator returned by [`IteratorExt::resample`](crate::IteratorExt::resample).
+pub struct Resample<I, S> {
+    iter: I,
+    core: ResampleCore<S>,
+}
+
+impl<I, S> Resample<I, S>
+where
+    I: Iterator<Item = S>,
+    S: Sample + Default,
+{
+    pub(crate) fn new(iter: I, from_rate: u32, to_rate: u32) -> Self {
+        Self {
+            iter,
+            core: ResampleCore::new(from_rate, to_rate),
+        }
+    }
+}
+
+impl<I, S> Iterator for Resample<I, S>
+where
+    I: Iterator<Item = S>,
+    S: Sample + Default,
+{
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.core.step() {
+                Step::NeedMore => {
+                    let sample = self.iter.next();
+                    self.core.feed(sample);
+                }
+                Step::Done => return None,
+                Step::Emit(value) => return Some(value),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_impl {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use tokio_stream::Stream;
+
+    use super::{ResampleCore, Step};
+    use crate::sample::Sample;
+
+    /// Stream returned by [`StreamExt::resample`](crate::StreamExt::resample).
+    pub struct ResampleStream<T, S> {
+        stream: T,
+        core: ResampleCore<S>,
+    }
+
+    impl<T, S> ResampleStream<T, S>
+    where
+        T: Stream<Item = S>,
+        S: Sample + Default,
+    {
+        pub(crate) fn new(stream: T, from_rate: u32, to_rate: u32) -> Self {
+            Self {
+                stream,
+                core: ResampleCore::new(from_rate, to_rate),
+            }
+        }
+    }
+
+    impl<T, S> Stream for ResampleStream<T, S>
+    where
+        T: Stream<Item = S> + Unpin,
+        S: Sample + Default,
+    {
+        type Item = f32;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let this = self.get_mut();
+
+            loop {
+                match this.core.step() {
+                    Step::NeedMore => match Pin::new(&mut this.stream).poll_next(cx) {
+                        Poll::Ready(sample) => this.core.feed(sample),
+                        Poll::Pending => return Poll::Pending,
+                    },
+                    Step::Done => return Poll::Ready(None),
+                    Step::Emit(value) => return Poll::Ready(Some(value)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub use async_impl::ResampleStream;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IteratorExt;
+
+    #[test]
+    fn sinc_kernel_decays_to_zero_at_stale_tap_but_not_near_center() {
+        let half_width = KERNEL_HALF_WIDTH as f32;
+        // The oldest tap the ring ever supplies sits near `2 * half_width`;
+        // the one-sided window should have decayed to (near) zero there
+        // instead of aliasing back up toward full weight.
+        assert!(sinc_kernel(2.0 * half_width - 0.5).abs() < 1e-3);
+        // The tap nearest the read position should still carry most of
+        // its raw sinc weight.
+        assert!(sinc_kernel(0.5).abs() > 0.5);
+    }
+
+    #[test]
+    fn resample_core_needs_a_real_sample_before_emitting() {
+        let mut core = ResampleCore::<f32>::new(16_000, 16_000);
+        // the ring starts zero-primed with no real samples fed in yet.
+        assert!(matches!(core.step(), Step::NeedMore));
+        core.feed(Some(1.0));
+        assert!(matches!(core.step(), Step::Emit(_)));
+    }
+
+    #[test]
+    fn resample_drains_the_buffered_tail_after_the_source_ends() {
+        let input = vec![1.0f32, 0.5, -0.5, -1.0];
+        let output: Vec<f32> = input.clone().into_iter().resample(16_000, 16_000).collect();
+
+        // the ring still holds buffered samples once the source ends, so
+        // output keeps flowing past the last real input instead of
+        // stopping abruptly.
+        assert!(output.len() > input.len());
+    }
+}
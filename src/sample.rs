@@ -0,0 +1,27 @@
+/// A single PCM sample consumable by [`VoiceActivityDetector`](crate::VoiceActivityDetector).
+///
+/// Implemented for the sample types common decoders hand back (`i16` from
+/// [`hound`](https://docs.rs/hound), `f32` from most resamplers) so callers
+/// don't need to convert up front.
+pub trait Sample: Copy + Unpin {
+    /// Converts this sample to the `[-1.0, 1.0]` range the model expects.
+    fn to_f32(self) -> f32;
+}
+
+impl Sample for i16 {
+    fn to_f32(self) -> f32 {
+        self as f32 / i16::MAX as f32
+    }
+}
+
+impl Sample for i32 {
+    fn to_f32(self) -> f32 {
+        self as f32 / i32::MAX as f32
+    }
+}
+
+impl Sample for f32 {
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
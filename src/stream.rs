@@ -0,0 +1,186 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio_stream::Stream;
+
+use crate::label::{LabelConfig, LabelStream};
+use crate::mono::ToMonoStream;
+use crate::rechunk::RechunkStream;
+use crate::resample::ResampleStream;
+use crate::sample::Sample;
+use crate::vad::VoiceActivityDetector;
+
+/// Adapters that turn a plain sample stream into a VAD pipeline.
+pub trait StreamExt: Stream {
+    /// Classifies the stream in chunks of `N` samples, yielding each chunk
+    /// alongside its speech probability.
+    ///
+    /// The final chunk is yielded as-is (shorter than `N`) rather than
+    /// padded or dropped.
+    fn predict<const N: usize>(self, vad: VoiceActivityDetector<N>) -> PredictStream<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Sample,
+    {
+        PredictStream {
+            stream: self,
+            vad,
+            chunk: Vec::with_capacity(N),
+        }
+    }
+
+    /// Like [`predict`](StreamExt::predict), but for a source that's
+    /// already chunked (e.g. the output of [`rechunk`](StreamExt::rechunk))
+    /// instead of individual samples.
+    ///
+    /// Each chunk is classified as-is rather than re-sliced to `N`, so this
+    /// is the adapter to reach for once something else has already grouped
+    /// the stream into chunks.
+    fn predict_chunks<S, const N: usize>(
+        self,
+        vad: VoiceActivityDetector<N>,
+    ) -> PredictChunksStream<Self, N>
+    where
+        Self: Sized + Stream<Item = Vec<S>>,
+        S: Sample,
+    {
+        PredictChunksStream { stream: self, vad }
+    }
+
+    /// Runs a hysteresis state machine over [`predict`](StreamExt::predict)'s
+    /// per-chunk probabilities and coalesces them into [`Label`](crate::Label)
+    /// segments.
+    ///
+    /// See [`LabelConfig`] for the thresholds and durations that control
+    /// onset/offset debouncing and context padding.
+    fn label<const N: usize>(
+        self,
+        vad: VoiceActivityDetector<N>,
+        config: LabelConfig,
+    ) -> LabelStream<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Sample,
+    {
+        let sample_rate = vad.sample_rate();
+        LabelStream::new(self.predict(vad), sample_rate, config)
+    }
+
+    /// Resamples the stream from `from_rate` to `to_rate` using band-limited
+    /// sinc interpolation, so audio recorded at an arbitrary rate (e.g. a
+    /// 44.1 kHz or 48 kHz WAV) can be fed into [`predict`](StreamExt::predict)
+    /// at whatever rate the detector was constructed for.
+    fn resample(self, from_rate: u32, to_rate: u32) -> ResampleStream<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Sample + Default,
+    {
+        ResampleStream::new(self, from_rate, to_rate)
+    }
+
+    /// Downmixes an interleaved multi-channel stream (e.g. the output of
+    /// `reader.samples::<i16>()` on a stereo WAV) to mono by averaging
+    /// every group of `channels` consecutive samples.
+    ///
+    /// If the stream length isn't a multiple of `channels`, the final
+    /// frame is averaged over however many samples remain.
+    fn to_mono(self, channels: usize) -> ToMonoStream<Self>
+    where
+        Self: Sized + Stream<Item = i16>,
+    {
+        ToMonoStream::new(self, channels)
+    }
+
+    /// Re-chunks a stream of arbitrarily sized fragments (e.g. 960-sample
+    /// Opus packets) into exactly-`N`-sample chunks suitable for
+    /// [`predict_chunks`](StreamExt::predict_chunks).
+    ///
+    /// The final fragment is zero-padded up to `N` rather than dropped, so
+    /// live sources don't lose their last partial chunk when the stream
+    /// ends.
+    fn rechunk<const N: usize>(self) -> RechunkStream<Self, N>
+    where
+        Self: Sized + Stream<Item = Vec<i16>>,
+    {
+        RechunkStream::new(self)
+    }
+}
+
+impl<T: Stream> StreamExt for T {}
+
+/// Stream returned by [`StreamExt::predict`].
+pub struct PredictStream<T, const N: usize>
+where
+    T: Stream,
+{
+    stream: T,
+    vad: VoiceActivityDetector<N>,
+    chunk: Vec<T::Item>,
+}
+
+impl<T, const N: usize> Stream for PredictStream<T, N>
+where
+    T: Stream + Unpin,
+    T::Item: Sample,
+{
+    type Item = (Vec<T::Item>, f32);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.chunk.len() == N {
+                let chunk = std::mem::replace(&mut this.chunk, Vec::with_capacity(N));
+                let probability = this.vad.predict(chunk.iter().copied());
+                return Poll::Ready(Some((chunk, probability)));
+            }
+
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(sample)) => this.chunk.push(sample),
+                Poll::Ready(None) => {
+                    if this.chunk.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    let chunk = std::mem::take(&mut this.chunk);
+                    let probability = this.vad.predict(chunk.iter().copied());
+                    return Poll::Ready(Some((chunk, probability)));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Stream returned by [`StreamExt::predict_chunks`].
+pub struct PredictChunksStream<T, const N: usize> {
+    stream: T,
+    vad: VoiceActivityDetector<N>,
+}
+
+impl<T, S, const N: usize> Stream for PredictChunksStream<T, N>
+where
+    T: Stream<Item = Vec<S>> + Unpin,
+    S: Sample,
+{
+    type Item = (Vec<S>, f32);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                // An empty chunk has nothing to classify and would panic
+                // deep inside `VoiceActivityDetector::predict`; skip it
+                // rather than trusting the upstream stream never produces
+                // one.
+                Poll::Ready(Some(chunk)) if chunk.is_empty() => continue,
+                Poll::Ready(Some(chunk)) => {
+                    let probability = this.vad.predict(chunk.iter().copied());
+                    return Poll::Ready(Some((chunk, probability)));
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
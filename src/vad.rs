@@ -0,0 +1,101 @@
+use ndarray::Array3;
+use ort::session::Session;
+use ort::value::Tensor;
+
+use crate::error::Error;
+use crate::sample::Sample;
+
+const SUPPORTED_SAMPLE_RATES: [u32; 2] = [8_000, 16_000];
+const LSTM_STATE_SHAPE: (usize, usize, usize) = (2, 1, 64);
+
+/// A Silero-based voice activity detector that classifies fixed-size chunks
+/// of `N` samples as speech or non-speech.
+///
+/// `N` is the chunk size in samples, fixed at construction time so it's part
+/// of the type and can't drift between calls. The model itself only
+/// constrains the sample rate, not the chunk size.
+pub struct VoiceActivityDetector<const N: usize> {
+    session: Session,
+    sample_rate: u32,
+    h: Array3<f32>,
+    c: Array3<f32>,
+}
+
+impl<const N: usize> VoiceActivityDetector<N> {
+    /// Loads the bundled Silero model for the given sample rate.
+    ///
+    /// Returns [`Error::UnsupportedSampleRate`] unless `sample_rate` is
+    /// `8000` or `16000`, the only rates the model was trained on. Audio at
+    /// other rates should go through [`IteratorExt::resample`](crate::IteratorExt::resample)
+    /// first.
+    pub fn try_with_sample_rate(sample_rate: u32) -> Result<Self, Error> {
+        if !SUPPORTED_SAMPLE_RATES.contains(&sample_rate) {
+            return Err(Error::UnsupportedSampleRate(sample_rate));
+        }
+
+        let session = Session::builder()?
+            .commit_from_memory(include_bytes!(concat!(env!("OUT_DIR"), "/silero_vad.onnx")))?;
+
+        Ok(Self {
+            session,
+            sample_rate,
+            h: Array3::zeros(LSTM_STATE_SHAPE),
+            c: Array3::zeros(LSTM_STATE_SHAPE),
+        })
+    }
+
+    /// The sample rate this detector was constructed for.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Runs one chunk of samples through the model and returns the
+    /// probability, in `0.0..=1.0`, that it contains speech.
+    ///
+    /// The recurrent state is carried across calls, so chunks must be fed in
+    /// order for the result to be meaningful.
+    pub fn predict<S>(&mut self, chunk: impl IntoIterator<Item = S>) -> f32
+    where
+        S: Sample,
+    {
+        let input: Vec<f32> = chunk.into_iter().map(Sample::to_f32).collect();
+
+        let inputs = ort::inputs![
+            "input" => Tensor::from_array(([1, input.len()], input))
+                .expect("chunk shape is always valid"),
+            "sr" => Tensor::from_array(([1], vec![self.sample_rate as i64]))
+                .expect("scalar shape is always valid"),
+            "h" => Tensor::from_array(self.h.clone()).expect("state shape is always valid"),
+            "c" => Tensor::from_array(self.c.clone()).expect("state shape is always valid"),
+        ];
+
+        let outputs = self
+            .session
+            .run(inputs)
+            .expect("silero_vad.onnx is bundled and never rejects well-formed input");
+
+        self.h = outputs["hn"]
+            .try_extract_array::<f32>()
+            .expect("hn is always f32")
+            .to_owned()
+            .into_dimensionality()
+            .expect("hn always has the LSTM state shape");
+        self.c = outputs["cn"]
+            .try_extract_array::<f32>()
+            .expect("cn is always f32")
+            .to_owned()
+            .into_dimensionality()
+            .expect("cn always has the LSTM state shape");
+
+        outputs["output"]
+            .try_extract_tensor::<f32>()
+            .expect("output is always f32")
+            .1[0]
+    }
+
+    /// Resets the recurrent state, e.g. between unrelated audio streams.
+    pub fn reset(&mut self) {
+        self.h.fill(0.0);
+        self.c.fill(0.0);
+    }
+}